@@ -23,19 +23,32 @@
 //! ]);
 //! ```
 
-#[macro_use] extern crate lazy_static;
-use regex::Regex;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::str::CharIndices;
+
+/// A dictionary-lookup callback: given a word, report whether it's known. Used to decide whether
+/// a `HyphenatedWord` or `Contraction` should be split into components a downstream pronunciation
+/// dictionary is more likely to recognize.
+pub type Dictionary = Rc<dyn Fn(&str) -> bool>;
 
 /// Sentence Tokenizer.
-///
-/// For now this is stateless. This library is in a very early state, but I intend to add
-/// preferences and dictionary lookup callback support. ("Intend to come back" are famous last
-/// words.)
 pub struct SentenceTokenizer {
+  // Whether to split a Contraction like "haven't" into its stem Word ("have") and clitic
+  // Contraction suffix ("n't"). Downstream pronunciation dictionaries often key on the stem.
+  // Ignored for contractions once a dictionary is configured; see `dictionary`.
+  split_contractions: bool,
+  // Whether an unrecognized HyphenatedWord is split into its component Words and a
+  // Punctuation::Dash. Only takes effect once a dictionary is configured.
+  split_hyphenated_words: bool,
+  // Whether Word values (and the Word parts produced by dictionary-fallback splitting) are
+  // lowercased.
+  lowercase: bool,
+  // Optional dictionary-lookup callback. When present, it -- not `split_contractions` -- decides
+  // whether a Contraction is split, and it gates whether `split_hyphenated_words` applies.
+  dictionary: Option<Dictionary>,
 }
 
-// TODO: Emdash,
-// TODO: Ellipsis,
 /// Punctuation marks
 #[derive(Clone, Debug, PartialEq)]
 pub enum Punctuation {
@@ -45,24 +58,46 @@ pub enum Punctuation {
   Comma,
   /// Dash: '-'
   Dash,
+  /// Em-dash, two or more '-' or a literal '—'. TTS reads this as a parenthetical break.
+  Emdash,
+  /// An ellipsis, two or more '.' or a literal '…'. TTS reads this as a pause.
+  Ellipsis,
   /// Exclamation: '!'
   Exclamation,
   /// Period: '.'
   Period,
   /// Question: '?'
   Question,
+  /// A run of mixed or repeated emphasis marks that isn't an ellipsis or em-dash, like '!!!!!'
+  /// or the interrobang '!?'. Holds the raw marks, e.g. `Repeated("!!!!!".into())`.
+  Repeated(String),
   /// Semicolon: ';'
   Semicolon,
 }
 
-// TODO: Currency/prices, ordinals, percentages, math symbols, emoji, etc.
+// TODO: Math symbols, etc.
 /// A parsed token
 #[derive(Clone, Debug, PartialEq)]
 pub enum Token {
+  /// A dotted initialism like 'U.S.A.', 'e.g.', or 'Ph.D.', or a known abbreviation like 'Mr.'
+  /// or 'etc.'. Interior and trailing dots are kept attached so they aren't mistaken for
+  /// sentence-ending `Punctuation::Period`.
+  Acronym(String),
   /// A comma-formatted integer. Like Integer, but has comma separators.
   CommaFormattedInteger(String),
   /// A comma-formatted real number. Like RealNumber, but has comma separators.
   CommaFormattedRealNumber(String),
+  /// A word with an internal apostrophe: a contraction like "haven't" or "I'm", a leading-
+  /// apostrophe clipped form like "'em" or "'tis", or a genitive like "o'neill's". When the
+  /// tokenizer is configured to split clitics, this also holds just the clitic suffix (e.g.
+  /// "n't", "'m") of a split contraction, paired with a preceding Word stem.
+  Contraction(String),
+  /// A currency amount, like '$5' or '$1,234.56', including its leading currency symbol.
+  Currency(String),
+  /// An ASCII emoticon, like ':)', ':-D', or '8-)'.
+  Emoticon(String),
+  /// A heart glyph made of ASCII characters, like '<3' or '<333'.
+  Heart(String),
   /// Twitter-style hashtag, which matches '^#\w+$'.
   Hashtag(String),
   /// A hyphenated word matches '^([A-Za-z]+\-)+[A-Za-z]+$'.
@@ -70,6 +105,10 @@ pub enum Token {
   HyphenatedWord(String),
   /// A simple integer. Matches '\d+'
   Integer(String),
+  /// An ordinal number, like '1st', '2nd', '22nd', or '3rd'.
+  Ordinal(String),
+  /// A percentage, like '50%' or '3.5%', including its trailing '%'.
+  Percentage(String),
   /// A punctuation mark.
   Punctuation(Punctuation),
   /// A simple real number. Matches '\d+\.\d+'
@@ -84,12 +123,155 @@ pub enum Token {
   Unknown(String),
 }
 
+/// A byte-offset span into the original input string that a token was lexed from. Useful for
+/// mapping tokens (and, downstream, synthesized audio) back to the source text, e.g. for
+/// word-level highlighting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TokenSpan {
+  /// Byte offset of the span's first byte, inclusive.
+  pub start: usize,
+  /// Byte offset one past the span's last byte, exclusive.
+  pub end: usize,
+}
+
+/// A lazy iterator over `(TokenSpan, Token)` pairs, produced by `SentenceTokenizer::token_stream`.
+///
+/// Scans its source string exactly once via `char_indices`, classifying one whitespace-delimited
+/// run at a time instead of materializing the whole sentence into a `Vec` up front and running the
+/// classification passes over that whole, document-sized `Vec` repeatedly. Each run is classified
+/// by hand-written scanners (`detect_emoticons`, `detect_acronyms`, `separate_end_punctuation`,
+/// etc.) that dispatch on each run's leading character and walk it byte-by-byte rather than
+/// compiling and matching regexes, scoped to a small per-run buffer instead of the whole document;
+/// a run that decomposes into more than one token is queued there and drained before the scan
+/// advances. This removes both the old pipeline's regex matching and its `Vec::insert` calls into
+/// a document-sized `Vec` (previously worst-case quadratic). Tokens are still built as owned
+/// `String`s -- a zero-copy `Token<'a>` borrowing from `source` would need `Token` itself to grow
+/// a lifetime parameter, which would break the existing `Vec<Token>`-returning API this module
+/// keeps for current callers.
+pub struct TokenStream<'a> {
+  source: &'a str,
+  chars: CharIndices<'a>,
+  split_contractions: bool,
+  split_hyphenated_words: bool,
+  lowercase: bool,
+  dictionary: Option<Dictionary>,
+  pending: VecDeque<(TokenSpan, Token)>,
+  exhausted: bool,
+}
+
+impl<'a> Iterator for TokenStream<'a> {
+  type Item = (TokenSpan, Token);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      if let Some(token) = self.pending.pop_front() {
+        return Some(token);
+      }
+      if self.exhausted {
+        return None;
+      }
+
+      let mut start = None;
+      let mut end = None;
+
+      for (i, c) in self.chars.by_ref() {
+        if c.is_whitespace() {
+          if start.is_some() {
+            end = Some(i);
+            break;
+          }
+        } else if start.is_none() {
+          start = Some(i);
+        }
+      }
+
+      let start = match start {
+        Some(start) => start,
+        None => {
+          self.exhausted = true;
+          return None;
+        },
+      };
+      let end = match end {
+        Some(end) => end,
+        None => {
+          self.exhausted = true;
+          self.source.len()
+        },
+      };
+
+      let run = &self.source[start..end];
+      let mut tokens = vec![(TokenSpan { start, end }, Token::Unknown(run.to_string()))];
+
+      // Run the same classification passes used everywhere else, just scoped to this one run's
+      // small local buffer, so behavior (and spans) stay identical to scanning the whole
+      // sentence at once.
+      SentenceTokenizer::detect_emoticons(&mut tokens);
+      SentenceTokenizer::detect_acronyms(&mut tokens);
+      SentenceTokenizer::separate_end_punctuation(&mut tokens);
+      SentenceTokenizer::parse_integers_and_reals(&mut tokens);
+      SentenceTokenizer::parse_words_etc(
+        &mut tokens,
+        self.split_contractions,
+        self.split_hyphenated_words,
+        self.lowercase,
+        self.dictionary.as_ref(),
+      );
+
+      self.pending.extend(tokens);
+    }
+  }
+}
+
 impl SentenceTokenizer {
   /// Constructor.
   pub fn new() -> Self {
-    // TODO: I'm 99% sure I'll pick up this work and make this configurable, but right now
-    //  it has no state and making this a struct is overblown.
-    Self {}
+    Self {
+      split_contractions: false,
+      split_hyphenated_words: false,
+      lowercase: false,
+      dictionary: None,
+    }
+  }
+
+  /// Configure whether contractions are split into a stem Word and a clitic Contraction suffix,
+  /// e.g. "haven't" -> Word("have"), Contraction("n't"). Defaults to false. Ignored for
+  /// contractions once `with_dictionary` is set: the dictionary decides instead.
+  pub fn with_contraction_splitting(mut self, split_contractions: bool) -> Self {
+    self.split_contractions = split_contractions;
+    self
+  }
+
+  /// Configure whether a `HyphenatedWord` the dictionary doesn't recognize is split into its
+  /// component Words and a `Punctuation::Dash`, e.g. "drive-thru" -> Word("drive"),
+  /// Punctuation::Dash, Word("thru"). Only takes effect once `with_dictionary` is set. Defaults
+  /// to false.
+  pub fn with_hyphenation_splitting(mut self, split_hyphenated_words: bool) -> Self {
+    self.split_hyphenated_words = split_hyphenated_words;
+    self
+  }
+
+  /// Configure whether Word values (and the Word parts produced by dictionary-fallback
+  /// splitting) are lowercased. Defaults to false.
+  pub fn with_lowercase_normalization(mut self, lowercase: bool) -> Self {
+    self.lowercase = lowercase;
+    self
+  }
+
+  /// Configure a dictionary-lookup callback used to test whether a word is known. When set, any
+  /// simple `Contraction` (a single internal apostrophe, like "I'm") the callback reports as
+  /// unknown is split into a stem Word and clitic Contraction suffix; leading-apostrophe and
+  /// multi-apostrophe forms like "'nuff" or "o'neill's" are left intact. Any `HyphenatedWord` it reports as unknown is split into its
+  /// component Words and a `Punctuation::Dash` if `with_hyphenation_splitting` is also enabled.
+  /// This is the fallback `HyphenatedWord`'s own doc comment describes: when the whole compound
+  /// or contraction isn't in your dictionary, splitting it gives a downstream pronunciation
+  /// lookup a chance at the parts instead.
+  pub fn with_dictionary<F>(mut self, dictionary: F) -> Self
+  where
+    F: Fn(&str) -> bool + 'static,
+  {
+    self.dictionary = Some(Rc::new(dictionary));
+    self
   }
 
   /// Turn a text sequence into a series of tokens.
@@ -106,90 +288,362 @@ impl SentenceTokenizer {
   /// ]);
   /// ```
   pub fn tokenize(&self, sequence: &str) -> Vec<Token> {
-    let split = sequence.split(char::is_whitespace);
-    let mut tokens = Vec::new();
+    self.token_stream(sequence).map(|(_, token)| token).collect()
+  }
 
-    for s in split {
-      let trim = s.trim();
-      if trim.len() == 0 {
-        continue;
+  /// Turn a text sequence into a series of tokens, each paired with the `TokenSpan` of the
+  /// original input it was lexed from. TTS callers that need to align synthesized audio back to
+  /// the source text (e.g. word-level highlighting) should use this instead of `tokenize`.
+  ///
+  /// ```rust
+  /// use sentence::{SentenceTokenizer, Token, Punctuation, TokenSpan};
+  /// let tokenizer = SentenceTokenizer::new();
+  /// let tokens = tokenizer.tokenize_spanned("Hi, world!");
+  /// assert_eq!(tokens, vec![
+  ///   (TokenSpan { start: 0, end: 2 }, Token::Word("Hi".into())),
+  ///   (TokenSpan { start: 2, end: 3 }, Token::Punctuation(Punctuation::Comma)),
+  ///   (TokenSpan { start: 4, end: 9 }, Token::Word("world".into())),
+  ///   (TokenSpan { start: 9, end: 10 }, Token::Punctuation(Punctuation::Exclamation)),
+  /// ]);
+  /// ```
+  pub fn tokenize_spanned(&self, sequence: &str) -> Vec<(TokenSpan, Token)> {
+    self.token_stream(sequence).collect()
+  }
+
+  /// Lazily tokenize a text sequence, scanning `sequence` exactly once instead of building the
+  /// whole `Vec<Token>` up front. Prefer this over `tokenize`/`tokenize_spanned` when a caller can
+  /// consume tokens as they're produced (e.g. streaming them to a synthesizer) without needing the
+  /// whole sentence materialized.
+  pub fn token_stream<'a>(&self, sequence: &'a str) -> TokenStream<'a> {
+    TokenStream {
+      source: sequence,
+      chars: sequence.char_indices(),
+      split_contractions: self.split_contractions,
+      split_hyphenated_words: self.split_hyphenated_words,
+      lowercase: self.lowercase,
+      dictionary: self.dictionary.clone(),
+      pending: VecDeque::new(),
+      exhausted: false,
+    }
+  }
+
+  // Within a token sequence, replace leading emoticon/heart runs in Unknown("word") tokens with
+  // an Emoticon or Heart token, leaving any trailing characters (e.g. sentence punctuation) in a
+  // new Unknown token so that separate_end_punctuation can still peel it off afterward.
+  fn detect_emoticons(tokens: &mut Vec<(TokenSpan, Token)>) {
+    let mut i = 0;
+
+    while i < tokens.len() {
+      let token = if let Some((_, Token::Unknown(token))) = tokens.get(i) {
+        token
+      } else {
+        i += 1;
+        continue
+      };
+
+      let (len, is_heart) = if let Some(len) = Self::match_heart(token) {
+        (len, true)
+      } else if let Some(len) = Self::match_emoticon(token) {
+        (len, false)
+      } else {
+        i += 1;
+        continue
+      };
+
+      let matched = token[..len].to_string();
+      let after = token.get(len..)
+          .filter(|s| s.len() > 0)
+          .map(|s| s.to_string());
+
+      let span = tokens[i].0;
+      let split = span.start + matched.len();
+
+      if let Some(elem) = tokens.get_mut(i) {
+        elem.0 = TokenSpan { start: span.start, end: split };
+        elem.1 = if is_heart { Token::Heart(matched) } else { Token::Emoticon(matched) };
+      }
+      i += 1;
+
+      // Re-examine the remainder in place (don't advance past it) so back-to-back
+      // emoticons/hearts with no separating whitespace, e.g. "<3<3<3" or ":):):)", are each
+      // recognized instead of only the first.
+      if let Some(after) = after {
+        tokens.insert(i, (TokenSpan { start: split, end: span.end }, Token::Unknown(after)));
       }
-      tokens.push(Token::Unknown(s.to_string()));
     }
+  }
 
-    // TODO: None of this is efficient.
-    Self::separate_end_punctuation(&mut tokens);
-    Self::parse_integers_and_reals(&mut tokens);
-    Self::parse_words_etc(&mut tokens);
+  // Matches a leading heart glyph, '<' followed by one or more '3's, e.g. "<3" or "<333".
+  // Returns the byte length of the match.
+  fn match_heart(s: &str) -> Option<usize> {
+    let b = s.as_bytes();
+    if b.first() != Some(&b'<') || b.get(1) != Some(&b'3') {
+      return None;
+    }
+    let mut end = 2;
+    while b.get(end) == Some(&b'3') {
+      end += 1;
+    }
+    Some(end)
+  }
 
-    tokens
+  fn is_emoticon_brow(b: u8) -> bool {
+    b == b'<' || b == b'>'
   }
 
-  // Within a token sequence, replace tokens like Unknown("word.") with two tokens,
-  // one of which is the appropriate punctuation mark, and the other Unknown("word").
-  fn separate_end_punctuation(tokens: &mut Vec<Token>) {
-    lazy_static! {
-      static ref PUNCTUATION: Regex = Regex::new(r"([\.\?\-:!,;]+)$").unwrap();
+  fn is_emoticon_eyes(b: u8) -> bool {
+    matches!(b, b':' | b';' | b'=' | b'8')
+  }
+
+  fn is_emoticon_nose(b: u8) -> bool {
+    matches!(b, b'-' | b'o' | b'O' | b'*' | b'\'')
+  }
+
+  fn is_emoticon_mouth(b: u8) -> bool {
+    matches!(b, b')' | b']' | b'(' | b'[' | b'd' | b'D' | b'p' | b'P' | b'/' | b':' | b'}' | b'{' | b'@' | b'|' | b'\\')
+  }
+
+  // Matches a leading ASCII emoticon, dispatching on the leading byte: either the eyes-first form
+  // (optional brow, eyes, optional nose, one mouth character) or its mirror image (one mouth
+  // character, optional nose, eyes, optional brow). Each class is checked for exactly one
+  // character -- matching the mouth/brow position lazily rather than greedily -- so that
+  // back-to-back emoticons with no separating whitespace, like ":):):)", aren't swallowed by a
+  // single greedy match. Returns the byte length of the match.
+  fn match_emoticon(s: &str) -> Option<usize> {
+    Self::match_emoticon_eyes_first(s).or_else(|| Self::match_emoticon_mouth_first(s))
+  }
+
+  fn match_emoticon_eyes_first(s: &str) -> Option<usize> {
+    let b = s.as_bytes();
+    let mut i = 0;
+
+    if b.first().copied().is_some_and(Self::is_emoticon_brow) {
+      i += 1;
+    }
+    if !b.get(i).copied().is_some_and(Self::is_emoticon_eyes) {
+      return None;
+    }
+    i += 1;
+    if b.get(i).copied().is_some_and(Self::is_emoticon_nose) {
+      i += 1;
+    }
+    if !b.get(i).copied().is_some_and(Self::is_emoticon_mouth) {
+      return None;
+    }
+    Some(i + 1)
+  }
+
+  fn match_emoticon_mouth_first(s: &str) -> Option<usize> {
+    let b = s.as_bytes();
+    let mut i = 0;
+
+    if !b.first().copied().is_some_and(Self::is_emoticon_mouth) {
+      return None;
+    }
+    i += 1;
+    if b.get(i).copied().is_some_and(Self::is_emoticon_nose) {
+      i += 1;
+    }
+    if !b.get(i).copied().is_some_and(Self::is_emoticon_eyes) {
+      return None;
+    }
+    i += 1;
+    if b.get(i).copied().is_some_and(Self::is_emoticon_brow) {
+      i += 1;
     }
+    Some(i)
+  }
+
+  // Within a token sequence, replace a leading dotted-initialism or known-abbreviation run in
+  // Unknown("word") tokens with an Acronym token, leaving any trailing characters (e.g. a comma
+  // that follows "U.S.A.") in a new Unknown token so that separate_end_punctuation can still
+  // peel those off afterward. This must run before separate_end_punctuation so interior and
+  // trailing dots in acronyms aren't stripped off as Punctuation::Period.
+  fn detect_acronyms(tokens: &mut Vec<(TokenSpan, Token)>) {
+    // Known lowercase abbreviations that keep their trailing dot instead of it being read as
+    // the end of a sentence.
+    const KNOWN_ABBREVIATIONS: &[&str] =
+        &["mr", "mrs", "ms", "dr", "prof", "jr", "sr", "st", "vs", "etc", "inc"];
 
     let mut i = 0;
 
     while i < tokens.len() {
-      // TODO: We might benefit from a custom iterator.
-      let token = if let Some(Token::Unknown(token)) = tokens.get(i) {
+      let token = if let Some((_, Token::Unknown(token))) = tokens.get(i) {
         token
       } else {
         i += 1;
         continue
       };
 
-      let (before, punctuation, after)
-          = if let Some(mat) = PUNCTUATION.find(token)
+      let matched = if let Some(len) = Self::match_acronym(token) {
+        Self::shorten_glued_acronym_run(&token[..len])
+      } else if let Some(len) = Self::match_abbreviation(token) {
+        let word = &token[..len - 1];
+        if KNOWN_ABBREVIATIONS.contains(&word.to_lowercase().as_str()) {
+          token[..len].to_string()
+        } else {
+          i += 1;
+          continue
+        }
+      } else {
+        i += 1;
+        continue
+      };
+
+      let after = token.get(matched.len()..)
+          .filter(|s| s.len() > 0)
+          .map(|s| s.to_string());
+
+      let span = tokens[i].0;
+      let split = span.start + matched.len();
+
+      if let Some(elem) = tokens.get_mut(i) {
+        elem.0 = TokenSpan { start: span.start, end: split };
+        elem.1 = Token::Acronym(matched);
+      }
+      i += 1;
+
+      // Re-examine the remainder in place (don't advance past it) so back-to-back acronyms
+      // with no separating whitespace, e.g. "U.S.A.Ph.D.", are each recognized instead of only
+      // the first.
+      if let Some(after) = after {
+        tokens.insert(i, (TokenSpan { start: split, end: span.end }, Token::Unknown(after)));
+      }
+    }
+  }
+
+  // Matches a leading dotted-initialism like "U.S.A." or "Ph.D.": one or more letters, then one
+  // or more ".letters" groups, with an optional trailing dot. Returns the byte length of the
+  // match, dispatching purely on ASCII-alphabetic/'.' byte checks instead of a regex.
+  fn match_acronym(s: &str) -> Option<usize> {
+    let b = s.as_bytes();
+    let mut i = 0;
+    while b.get(i).copied().is_some_and(|c| c.is_ascii_alphabetic()) {
+      i += 1;
+    }
+    if i == 0 {
+      return None;
+    }
+
+    let mut groups = 0;
+    while b.get(i) == Some(&b'.') {
+      let mut j = i + 1;
+      while b.get(j).copied().is_some_and(|c| c.is_ascii_alphabetic()) {
+        j += 1;
+      }
+      if j == i + 1 {
+        break;
+      }
+      i = j;
+      groups += 1;
+    }
+    if groups == 0 {
+      return None;
+    }
+
+    if b.get(i) == Some(&b'.') {
+      i += 1;
+    }
+
+    Some(i)
+  }
+
+  // Matches a leading "Word." abbreviation, e.g. "Mr.": a letter run immediately followed by a
+  // single dot. Used as a fallback for known lowercase abbreviations that `match_acronym`'s
+  // multi-group grammar doesn't cover. Returns the byte length of the match.
+  fn match_abbreviation(s: &str) -> Option<usize> {
+    let b = s.as_bytes();
+    let mut i = 0;
+    while b.get(i).copied().is_some_and(|c| c.is_ascii_alphabetic()) {
+      i += 1;
+    }
+    if i == 0 || b.get(i) != Some(&b'.') {
+      return None;
+    }
+    Some(i + 1)
+  }
+
+  // The acronym grammar is self-similar (a dotted-initialism run looks just like another dotted-
+  // initialism run), so a greedy match of back-to-back acronyms with no separating whitespace,
+  // e.g. "U.S.A.Ph.D.", swallows both into one token. Trim the greedy match back to the longest
+  // leading prefix that is itself a complete, standalone acronym *and* whose remainder also looks
+  // like the start of a new acronym, so "U.S.A.Ph.D." splits into "U.S.A." and "Ph.D." instead of
+  // merging. If no such split exists, the original (possibly multi-group, e.g. "Ph.D.") match is
+  // a single acronym and is returned unchanged.
+  fn shorten_glued_acronym_run(raw: &str) -> String {
+    let dot_positions: Vec<usize> = raw.match_indices('.').map(|(idx, _)| idx).collect();
+    for &idx in dot_positions.iter().rev() {
+      let split = idx + 1;
+      if split >= raw.len() {
+        continue;
+      }
+      let (prefix, suffix) = raw.split_at(split);
+      let prefix_is_whole_acronym = Self::match_acronym(prefix) == Some(prefix.len());
+      let suffix_is_whole_acronym = Self::match_acronym(suffix) == Some(suffix.len());
+      if prefix_is_whole_acronym && suffix_is_whole_acronym {
+        return prefix.to_string();
+      }
+    }
+
+    raw.to_string()
+  }
+
+  // Within a token sequence, replace tokens like Unknown("word.") with two tokens,
+  // one of which is the appropriate punctuation mark, and the other Unknown("word").
+  fn separate_end_punctuation(tokens: &mut Vec<(TokenSpan, Token)>) {
+    let mut i = 0;
+
+    while i < tokens.len() {
+      let token = if let Some((_, Token::Unknown(token))) = tokens.get(i) {
+        token
+      } else {
+        i += 1;
+        continue
+      };
+
+      let (before, punctuation, match_start, match_end, after)
+          = if let Some(match_start) = Self::match_trailing_punctuation(token)
       {
-        let punctuation = match mat.as_str() {
-          "!" => Punctuation::Exclamation,
-          "," => Punctuation::Comma,
-          "-" => Punctuation::Dash,
-          "." => Punctuation::Period,
-          ":" => Punctuation::Colon,
-          ";" => Punctuation::Semicolon,
-          "?" => Punctuation::Question,
-          _ => {
-            i += 1;
-            continue
-          },
-        };
-
-        let before = token.get(0..mat.start())
+        let match_end = token.len();
+        let punctuation = Self::classify_punctuation(&token[match_start..match_end]);
+
+        let before = token.get(0..match_start)
             .filter(|s| s.len() > 0)
             .map(|s| s.to_string());
 
-        let after = token.get(mat.end()..token.len())
+        let after = token.get(match_end..token.len())
             .filter(|s| s.len() > 0)
             .map(|s| s.to_string());
 
-        (before, punctuation, after)
+        (before, punctuation, match_start, match_end, after)
       } else {
         i += 1;
         continue
       };
 
+      let span = tokens[i].0;
+      let punct_start = span.start + match_start;
+      let punct_end = span.start + match_end;
+
       // String before the punctuation match
       let mut insert = false;
       if let Some(before) = before {
         if let Some(elem) = tokens.get_mut(i) {
-          *elem = Token::Unknown(before);
+          elem.0 = TokenSpan { start: span.start, end: punct_start };
+          elem.1 = Token::Unknown(before);
         }
         i += 1;
         insert = true;
       }
 
       // Punctuation
+      let punct_span = TokenSpan { start: punct_start, end: punct_end };
       if insert {
-        tokens.insert(i, Token::Punctuation(punctuation));
+        tokens.insert(i, (punct_span, Token::Punctuation(punctuation)));
       } else {
         if let Some(elem) = tokens.get_mut(i) {
-          *elem = Token::Punctuation(punctuation);
+          elem.0 = punct_span;
+          elem.1 = Token::Punctuation(punctuation);
         }
       }
 
@@ -197,75 +651,421 @@ impl SentenceTokenizer {
 
       // String after the punctuation match
       if let Some(after) = after {
-        tokens.insert(i, Token::Unknown(after));
+        tokens.insert(i, (TokenSpan { start: punct_end, end: span.end }, Token::Unknown(after)));
         i += 1;
       }
     }
   }
 
-  // Materialize Unknown("\d+") and Unknown("\d+\.\d+") sequences into integer and real tokens.
-  fn parse_integers_and_reals(tokens: &mut Vec<Token>) {
-    lazy_static! {
-      static ref REALS : Regex = Regex::new(r"^\d+\.\d+$").unwrap();
-      static ref INTEGERS : Regex = Regex::new(r"^\d+$").unwrap();
-      static ref COMMA_FORMATTED_REALS : Regex = Regex::new(r"^(\d+,)+\d+\.\d+$").unwrap();
-      static ref COMMA_FORMATTED_INTEGERS : Regex = Regex::new(r"^(\d+,)+\d+$").unwrap();
+  // The marks `classify_punctuation` understands as sentence-ending/separating punctuation.
+  fn is_trailing_punctuation_mark(c: char) -> bool {
+    matches!(c, '.' | '?' | '-' | ':' | '!' | ',' | ';' | '…' | '—')
+  }
+
+  // Finds the longest trailing run of `is_trailing_punctuation_mark` characters at the end of
+  // `s`, returning the byte offset where that run starts, or `None` if `s` doesn't end in one.
+  fn match_trailing_punctuation(s: &str) -> Option<usize> {
+    let mut start = s.len();
+    for (idx, c) in s.char_indices().rev() {
+      if Self::is_trailing_punctuation_mark(c) {
+        start = idx;
+      } else {
+        break;
+      }
+    }
+    if start == s.len() {
+      None
+    } else {
+      Some(start)
     }
+  }
 
-    for token in tokens.iter_mut() {
-      match token {
-        Token::Unknown(value) => {
-          if REALS.is_match(value) {
-            *token = Token::RealNumber(value.clone()); // TODO: Move instead.
-          }
-          else if INTEGERS.is_match(value) {
-            *token = Token::Integer(value.clone()); // TODO: Move instead.
-          }
-          else if COMMA_FORMATTED_REALS.is_match(value) {
-            *token = Token::CommaFormattedRealNumber(value.clone()); // TODO: Move instead.
-          }
-          else if COMMA_FORMATTED_INTEGERS.is_match(value) {
-            *token = Token::CommaFormattedInteger(value.clone()); // TODO: Move instead.
-          }
+  // Map a matched run of trailing punctuation marks to the Punctuation it represents. A single
+  // mark keeps its usual meaning. A run of two or more dots (or a literal '…') is an Ellipsis; a
+  // run of two or more dashes (or a literal '—') is an Emdash. Anything else repeated or mixed
+  // (e.g. "!!!!!", the interrobang "!?") collapses into one Repeated token instead of one
+  // Punctuation per character.
+  fn classify_punctuation(marks: &str) -> Punctuation {
+    let mut chars = marks.chars();
+    let first = chars.next().expect("punctuation match is never empty");
+
+    if chars.next().is_none() {
+      return match first {
+        '!' => Punctuation::Exclamation,
+        ',' => Punctuation::Comma,
+        '-' => Punctuation::Dash,
+        '.' => Punctuation::Period,
+        ':' => Punctuation::Colon,
+        ';' => Punctuation::Semicolon,
+        '?' => Punctuation::Question,
+        '…' => Punctuation::Ellipsis,
+        '—' => Punctuation::Emdash,
+        _ => Punctuation::Repeated(marks.to_string()),
+      };
+    }
+
+    if marks.chars().all(|c| c == '.' || c == '…') {
+      Punctuation::Ellipsis
+    } else if marks.chars().all(|c| c == '-' || c == '—') {
+      Punctuation::Emdash
+    } else {
+      Punctuation::Repeated(marks.to_string())
+    }
+  }
+
+  // Materialize Unknown("\d+") and Unknown("\d+\.\d+") sequences into integer and real tokens,
+  // along with the currency, percentage, and ordinal forms built on top of them. Those three are
+  // checked first so e.g. "$100" is recognized whole instead of becoming a stray '$' plus an
+  // Integer.
+  fn parse_integers_and_reals(tokens: &mut Vec<(TokenSpan, Token)>) {
+    for (_, token) in tokens.iter_mut() {
+      let parsed = match token {
+        Token::Unknown(value) => Self::classify_number_like(value),
+        _ => None,
+      };
+      if let Some(parsed) = parsed {
+        *token = parsed; // TODO: Move instead.
+      }
+    }
+  }
+
+  // `true` for the currency symbols recognized as a Currency prefix.
+  fn is_currency_symbol(c: char) -> bool {
+    matches!(c, '$' | '€' | '£' | '¥')
+  }
+
+  // `^(\d+,)*\d+$`: one or more comma-separated digit groups, the last without a trailing comma.
+  fn is_digit_groups(s: &str) -> bool {
+    if s.is_empty() {
+      return false;
+    }
+    s.split(',').all(|group| !group.is_empty() && group.bytes().all(|b| b.is_ascii_digit()))
+  }
+
+  // `^\d+$`.
+  fn is_plain_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+  }
+
+  // Splits `s` on the first '.', returning (integer part, fractional part) if both sides are
+  // non-empty and the fractional side is plain digits. The integer side is left for the caller
+  // to validate, since it may itself be comma-formatted.
+  fn split_decimal(s: &str) -> Option<(&str, &str)> {
+    let idx = s.find('.')?;
+    let (integer, fraction) = (&s[..idx], &s[idx + 1..]);
+    if integer.is_empty() || !Self::is_plain_digits(fraction) {
+      return None;
+    }
+    Some((integer, fraction))
+  }
+
+  // Classify an Unknown run as a currency amount, percentage, ordinal, or (comma-formatted)
+  // integer/real, in that priority order so e.g. "$100" is recognized whole instead of becoming
+  // a stray '$' plus an Integer. Returns None if `value` matches none of these number shapes.
+  fn classify_number_like(value: &str) -> Option<Token> {
+    // `^(\d+,)*\d+(\.\d+)?$`, i.e. the digit shape both Currency and Percentage share.
+    let is_amount = |rest: &str| match Self::split_decimal(rest) {
+      Some((integer, _)) => Self::is_digit_groups(integer),
+      None => Self::is_digit_groups(rest),
+    };
+
+    if value.strip_prefix(Self::is_currency_symbol).filter(|rest| is_amount(rest)).is_some() {
+      return Some(Token::Currency(value.to_string()));
+    } else if value.strip_suffix('%').filter(|rest| is_amount(rest)).is_some() {
+      return Some(Token::Percentage(value.to_string()));
+    } else if ["st", "nd", "rd", "th"].iter()
+        .find_map(|suffix| value.strip_suffix(suffix))
+        .filter(|rest| Self::is_plain_digits(rest))
+        .is_some()
+    {
+      return Some(Token::Ordinal(value.to_string()));
+    }
+
+    if let Some((integer, _)) = Self::split_decimal(value) {
+      if Self::is_plain_digits(integer) {
+        return Some(Token::RealNumber(value.to_string()));
+      } else if Self::is_digit_groups(integer) && integer.contains(',') {
+        return Some(Token::CommaFormattedRealNumber(value.to_string()));
+      }
+    } else if Self::is_plain_digits(value) {
+      return Some(Token::Integer(value.to_string()));
+    } else if Self::is_digit_groups(value) && value.contains(',') {
+      return Some(Token::CommaFormattedInteger(value.to_string()));
+    }
+
+    None
+  }
+
+  // Materialize Unknown("\w+") sequences into word tokens. `dictionary`, when present, decides
+  // whether a Contraction or (if `split_hyphenated_words`) a HyphenatedWord gets split, taking
+  // priority over the unconditional `split_contractions` preference.
+  fn parse_words_etc(
+    tokens: &mut Vec<(TokenSpan, Token)>,
+    split_contractions: bool,
+    split_hyphenated_words: bool,
+    lowercase: bool,
+    dictionary: Option<&Dictionary>,
+  ) {
+    for (_, token) in tokens.iter_mut() {
+      if let Token::Unknown(value) = token {
+        *token = Self::classify_word_like(value, lowercase);
+      }
+    }
+
+    if let Some(dictionary) = dictionary {
+      Self::apply_dictionary_fallback(tokens, dictionary.as_ref(), split_hyphenated_words, lowercase);
+    } else if split_contractions {
+      Self::split_contraction_clitics(tokens, lowercase);
+    }
+  }
+
+  // `\w` as used by the regex crate: word characters are letters, digits, and underscore.
+  fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+  }
+
+  // `^\w+$`.
+  fn is_word(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(Self::is_word_char)
+  }
+
+  // `^#\w+$`.
+  fn is_hashtag(s: &str) -> bool {
+    s.strip_prefix('#').is_some_and(Self::is_word)
+  }
+
+  // `^@\w+$`.
+  fn is_username(s: &str) -> bool {
+    s.strip_prefix('@').is_some_and(Self::is_word)
+  }
+
+  // `^([A-Za-z]+\-)+[A-Za-z]+$`: two or more letters-only runs joined by single hyphens.
+  fn is_hyphenated_word(s: &str) -> bool {
+    let mut parts = s.split('-');
+    parts.clone().count() > 1
+        && parts.all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphabetic()))
+  }
+
+  // `^[A-Za-z]+(['-]+[A-Za-z]+)+$`: a letters-only run followed by one or more groups of
+  // (`'`/`-` characters, then another letters-only run).
+  fn is_contraction(s: &str) -> bool {
+    let mut chars = s.chars().peekable();
+    let mut saw_group = false;
+
+    match chars.peek() {
+      Some(c) if c.is_ascii_alphabetic() => {
+        while chars.next_if(|c| c.is_ascii_alphabetic()).is_some() {}
+      },
+      _ => return false,
+    }
+
+    loop {
+      let mut saw_separator = false;
+      while chars.next_if(|&c| c == '\'' || c == '-').is_some() {
+        saw_separator = true;
+      }
+      if !saw_separator {
+        break;
+      }
+
+      let mut saw_letter = false;
+      while chars.next_if(|c| c.is_ascii_alphabetic()).is_some() {
+        saw_letter = true;
+      }
+      if !saw_letter {
+        return false;
+      }
+      saw_group = true;
+    }
+
+    saw_group && chars.next().is_none()
+  }
+
+  // `^'[A-Za-z]+$`: a leading apostrophe followed by one or more letters, e.g. "'em", "'tis".
+  fn is_leading_apostrophe_word(s: &str) -> bool {
+    s.strip_prefix('\'').is_some_and(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_alphabetic()))
+  }
+
+  // `^http(s)?://(\w+\.)+(\w+)/?([\w/#\?&=\.])*$`.
+  fn is_url(s: &str) -> bool {
+    let rest = s.strip_prefix("http://").or_else(|| s.strip_prefix("https://"));
+    let Some(rest) = rest else { return false };
+
+    let (host, path) = match rest.find('/') {
+      Some(idx) => (&rest[..idx], &rest[idx + 1..]),
+      None => (rest, ""),
+    };
+
+    Self::is_valid_url_host(host)
+        && path.chars().all(|c| Self::is_word_char(c) || matches!(c, '/' | '#' | '?' | '&' | '='| '.'))
+  }
+
+  // `^(\w+\.)+(\w+)$`: one or more dot-separated `\w+` labels.
+  fn is_valid_url_host(host: &str) -> bool {
+    let mut labels = host.split('.');
+    labels.clone().count() > 1 && labels.all(Self::is_word)
+  }
+
+  // Classify an Unknown run as a URL, hashtag, username mention, word, contraction, or
+  // hyphenated word, in that priority order, leaving it Unknown if none match.
+  fn classify_word_like(value: &str, lowercase: bool) -> Token {
+    if Self::is_url(value) {
+      Token::Url(value.to_string()) // TODO: Move instead.
+    } else if Self::is_hashtag(value) {
+      Token::Hashtag(value.to_string()) // TODO: Move instead.
+    } else if Self::is_username(value) {
+      Token::UsernameMention(value.to_string()) // TODO: Move instead.
+    } else if Self::is_word(value) {
+      Token::Word(Self::normalize_case(value, lowercase))
+    } else if value.contains('\'') && (Self::is_contraction(value) || Self::is_leading_apostrophe_word(value)) {
+      Token::Contraction(value.to_string()) // TODO: Move instead.
+    } else if Self::is_hyphenated_word(value) {
+      Token::HyphenatedWord(value.to_string()) // TODO: Move instead.
+    } else {
+      Token::Unknown(value.to_string())
+    }
+  }
+
+  // For each HyphenatedWord or simple Contraction the dictionary doesn't recognize, replace it
+  // with its split components: a HyphenatedWord becomes its hyphen-separated Words and
+  // Punctuation::Dash marks (gated by `split_hyphenated_words`), and a Contraction becomes a Word
+  // stem and clitic Contraction suffix, same as `split_contraction_clitics` but decided per-token
+  // by the lookup instead of unconditionally.
+  fn apply_dictionary_fallback(
+    tokens: &mut Vec<(TokenSpan, Token)>,
+    dictionary: &dyn Fn(&str) -> bool,
+    split_hyphenated_words: bool,
+    lowercase: bool,
+  ) {
+    let mut i = 0;
+    while i < tokens.len() {
+      let replacement = match &tokens[i].1 {
+        Token::HyphenatedWord(value) if split_hyphenated_words && !dictionary(value) => {
+          Some(Self::split_hyphenated(value, tokens[i].0.start, lowercase))
+        },
+        Token::Contraction(value) if Self::is_simple_contraction(value) && !dictionary(value) => {
+          Self::split_clitic(value).map(|(stem, clitic)| {
+            let span = tokens[i].0;
+            let split = span.start + stem.len();
+            vec![
+              (TokenSpan { start: span.start, end: split }, Token::Word(Self::normalize_case(&stem, lowercase))),
+              (TokenSpan { start: split, end: span.end }, Token::Contraction(clitic)),
+            ]
+          })
+        },
+        _ => None,
+      };
+
+      match replacement {
+        Some(parts) => {
+          let len = parts.len();
+          tokens.splice(i..i + 1, parts);
+          i += len;
         },
-        _ => continue,
+        None => i += 1,
       }
     }
   }
 
-  // Materialize Unknown("\w+") sequences into word tokens.
-  fn parse_words_etc(tokens: &mut Vec<Token>) {
-    lazy_static! {
-      static ref WORD : Regex = Regex::new(r"^\w+$").unwrap();
-      static ref HYPHENATED_WORD : Regex = Regex::new(r"^([A-Za-z]+\-)+[A-Za-z]+$").unwrap();
-      static ref URL : Regex = Regex::new(r"^http(s)?://(\w+\.)+(\w+)/?([\w/#\?&=\.])*$").unwrap();
-      static ref USERNAME : Regex = Regex::new(r"^@\w+$").unwrap();
-      static ref HASHTAG : Regex = Regex::new(r"^#\w+$").unwrap();
+  // Split a HyphenatedWord's raw text on '-' into alternating Word/Dash tokens, e.g.
+  // "drive-thru" -> Word("drive"), Punctuation::Dash, Word("thru"), with spans offset from
+  // `start` (the absolute byte offset of the run's first byte in the original source).
+  fn split_hyphenated(value: &str, start: usize, lowercase: bool) -> Vec<(TokenSpan, Token)> {
+    let mut parts = Vec::new();
+    let mut offset = start;
+
+    for (i, part) in value.split('-').enumerate() {
+      if i > 0 {
+        parts.push((TokenSpan { start: offset, end: offset + 1 }, Token::Punctuation(Punctuation::Dash)));
+        offset += 1;
+      }
+      let end = offset + part.len();
+      parts.push((TokenSpan { start: offset, end }, Token::Word(Self::normalize_case(part, lowercase))));
+      offset = end;
     }
 
-    for token in tokens.iter_mut() {
-      match token {
-        Token::Unknown(value) => {
-          if URL.is_match(value) {
-            *token = Token::Url(value.clone()); // TODO: Move instead.
-          }
-          else if HASHTAG.is_match(value) {
-            *token = Token::Hashtag(value.clone()); // TODO: Move instead.
-          }
-          else if USERNAME.is_match(value) {
-            *token = Token::UsernameMention(value.clone()); // TODO: Move instead.
-          }
-          else if WORD.is_match(value) {
-            *token = Token::Word(value.clone()); // TODO: Move instead.
-          }
-          else if HYPHENATED_WORD.is_match(value) {
-            *token = Token::HyphenatedWord(value.clone()); // TODO: Move instead.
-          }
+    parts
+  }
+
+  // Lowercase a token's text if `lowercase` normalization is enabled, otherwise return it as-is.
+  fn normalize_case(value: &str, lowercase: bool) -> String {
+    if lowercase {
+      value.to_lowercase()
+    } else {
+      value.to_string()
+    }
+  }
+
+  // Within a token sequence, replace each simple Contraction("stem'clitic") token with a Word
+  // stem followed by a Contraction clitic suffix, e.g. Contraction("haven't") becomes
+  // Word("have"), Contraction("n't"). Leading-apostrophe forms ("'em") and multi-apostrophe or
+  // hyphenated forms ("o'neill's", "will-o'-the-wisp") have no useful stem/clitic split and are
+  // left intact; a simple genitive like "donald's" is split like any other contraction.
+  fn split_contraction_clitics(tokens: &mut Vec<(TokenSpan, Token)>, lowercase: bool) {
+    let mut i = 0;
+
+    while i < tokens.len() {
+      let value = if let Some((_, Token::Contraction(value))) = tokens.get(i) {
+        value
+      } else {
+        i += 1;
+        continue
+      };
+
+      let (stem, clitic) = match Self::split_clitic(value) {
+        Some(parts) if Self::is_simple_contraction(value) => parts,
+        _ => {
+          i += 1;
+          continue
         },
-        _ => continue,
+      };
+
+      let span = tokens[i].0;
+      let split = span.start + stem.len();
+
+      if let Some(elem) = tokens.get_mut(i) {
+        elem.0 = TokenSpan { start: span.start, end: split };
+        elem.1 = Token::Word(Self::normalize_case(&stem, lowercase));
       }
+      i += 1;
+      tokens.insert(i, (TokenSpan { start: split, end: span.end }, Token::Contraction(clitic)));
+      i += 1;
+    }
+  }
+
+  // `^[A-Za-z]+'[A-Za-z]+$`: a single internal apostrophe splitting two letters-only runs, e.g.
+  // "haven't" or "I'm", but not a leading-apostrophe form like "'em" or a multi-apostrophe one.
+  fn is_simple_contraction(s: &str) -> bool {
+    match s.find('\'') {
+      Some(idx) if idx > 0 => {
+        let (stem, clitic) = (&s[..idx], &s[idx + 1..]);
+        stem.chars().all(|c| c.is_ascii_alphabetic())
+            && !clitic.is_empty()
+            && clitic.chars().all(|c| c.is_ascii_alphabetic())
+      },
+      _ => false,
     }
   }
+
+  // Split a contraction into its stem and apostrophe-led clitic suffix, e.g. "I'm" ->
+  // ("I", "'m"). The "n't" suffix is special-cased so the 'n' moves with the clitic instead of
+  // staying on the stem, e.g. "haven't" -> ("have", "n't") rather than ("haven", "'t").
+  fn split_clitic(word: &str) -> Option<(String, String)> {
+    let idx = word.find('\'')?;
+    if idx == 0 {
+      return None;
+    }
+
+    let split_at = if word[idx..].eq_ignore_ascii_case("'t")
+        && word[idx - 1..idx].eq_ignore_ascii_case("n")
+    {
+      idx - 1
+    } else {
+      idx
+    };
+
+    Some((word[..split_at].to_string(), word[split_at..].to_string()))
+  }
 }
 
 #[cfg(test)]
@@ -273,6 +1073,7 @@ mod tests {
   use crate::Punctuation;
   use crate::SentenceTokenizer;
   use crate::Token;
+  use crate::TokenSpan;
 
   #[test]
   fn simple_sentence() {
@@ -502,6 +1303,57 @@ mod tests {
     //  I want to work on dictionary lookup before adding it.
   }
 
+  #[test]
+  fn ellipsis_emdash_and_repeated_marks() {
+    let sentence = "Wait... is that true?";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("Wait".into()),
+      Token::Punctuation(Punctuation::Ellipsis),
+      Token::Word("is".into()),
+      Token::Word("that".into()),
+      Token::Word("true".into()),
+      Token::Punctuation(Punctuation::Question),
+    ]);
+    // A literal ellipsis glyph, and a longer run of dots.
+    let sentence = "Hold on… or not....";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("Hold".into()),
+      Token::Word("on".into()),
+      Token::Punctuation(Punctuation::Ellipsis),
+      Token::Word("or".into()),
+      Token::Word("not".into()),
+      Token::Punctuation(Punctuation::Ellipsis),
+    ]);
+    // Double-dash and a literal em-dash, both read as a parenthetical break.
+    let sentence = "It was -- somehow -- fine";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("It".into()),
+      Token::Word("was".into()),
+      Token::Punctuation(Punctuation::Emdash),
+      Token::Word("somehow".into()),
+      Token::Punctuation(Punctuation::Emdash),
+      Token::Word("fine".into()),
+    ]);
+    let sentence = "It was — somehow — fine";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("It".into()),
+      Token::Word("was".into()),
+      Token::Punctuation(Punctuation::Emdash),
+      Token::Word("somehow".into()),
+      Token::Punctuation(Punctuation::Emdash),
+      Token::Word("fine".into()),
+    ]);
+    // Repeated emphasis and the interrobang collapse into a single emphasized mark instead of
+    // one Punctuation per character.
+    let sentence = "yes!!!!! really!?";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("yes".into()),
+      Token::Punctuation(Punctuation::Repeated("!!!!!".into())),
+      Token::Word("really".into()),
+      Token::Punctuation(Punctuation::Repeated("!?".into())),
+    ]);
+  }
+
   #[test]
   fn urls() {
     let sentence = "Go to https://google.com";
@@ -565,6 +1417,296 @@ mod tests {
     ]);
   }
 
+  #[test]
+  fn emoticons() {
+    let sentence = "Great :) I'm happy";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("Great".into()),
+      Token::Emoticon(":)".into()),
+      Token::Contraction("I'm".into()),
+      Token::Word("happy".into()),
+    ]);
+    // Trailing sentence punctuation is still peeled off.
+    let sentence = "Great :).";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("Great".into()),
+      Token::Emoticon(":)".into()),
+      Token::Punctuation(Punctuation::Period),
+    ]);
+    // Nose and mirror-image forms.
+    let sentence = "Hi :-D and (: to you";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("Hi".into()),
+      Token::Emoticon(":-D".into()),
+      Token::Word("and".into()),
+      Token::Emoticon("(:".into()),
+      Token::Word("to".into()),
+      Token::Word("you".into()),
+    ]);
+    // Hearts.
+    let sentence = "I <3 you <333";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("I".into()),
+      Token::Heart("<3".into()),
+      Token::Word("you".into()),
+      Token::Heart("<333".into()),
+    ]);
+    // Back-to-back emoticons/hearts with no separating whitespace are each recognized on
+    // their own, rather than the first one swallowing the rest.
+    let sentence = "<3<3<3";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Heart("<3".into()),
+      Token::Heart("<3".into()),
+      Token::Heart("<3".into()),
+    ]);
+    let sentence = ":):):)";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Emoticon(":)".into()),
+      Token::Emoticon(":)".into()),
+      Token::Emoticon(":)".into()),
+    ]);
+  }
+
+  #[test]
+  fn contractions() {
+    let sentence = "haven't, I'm, o'clock, they'd";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Contraction("haven't".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Contraction("I'm".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Contraction("o'clock".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Contraction("they'd".into()),
+    ]);
+    // Leading-apostrophe clipped forms.
+    let sentence = "'tis the season, 'nuff said";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Contraction("'tis".into()),
+      Token::Word("the".into()),
+      Token::Word("season".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Contraction("'nuff".into()),
+      Token::Word("said".into()),
+    ]);
+    // Mixed hyphen/apostrophe compound.
+    let sentence = "will-o'-the-wisp";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Contraction("will-o'-the-wisp".into()),
+    ]);
+    // Genitive forms stay a single unit.
+    let sentence = "donald's hat and o'neill's coat";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Contraction("donald's".into()),
+      Token::Word("hat".into()),
+      Token::Word("and".into()),
+      Token::Contraction("o'neill's".into()),
+      Token::Word("coat".into()),
+    ]);
+  }
+
+  #[test]
+  fn contraction_clitic_splitting() {
+    let tokenizer = SentenceTokenizer::new().with_contraction_splitting(true);
+    assert_eq!(tokenizer.tokenize("haven't"), vec![
+      Token::Word("have".into()),
+      Token::Contraction("n't".into()),
+    ]);
+    assert_eq!(tokenizer.tokenize("I'm sorry"), vec![
+      Token::Word("I".into()),
+      Token::Contraction("'m".into()),
+      Token::Word("sorry".into()),
+    ]);
+    // Leading-apostrophe and mixed compound forms have no clean stem, so they're left intact.
+    assert_eq!(tokenizer.tokenize("'nuff"), vec![
+      Token::Contraction("'nuff".into()),
+    ]);
+    assert_eq!(tokenizer.tokenize("will-o'-the-wisp"), vec![
+      Token::Contraction("will-o'-the-wisp".into()),
+    ]);
+  }
+
+  #[test]
+  fn dictionary_fallback_splitting() {
+    let known = ["drive", "thru", "thus", "haven't"];
+    let tokenizer = SentenceTokenizer::new()
+        .with_hyphenation_splitting(true)
+        .with_dictionary(move |word: &str| known.contains(&word));
+
+    // "drive-thru" isn't known whole, so it falls back to its parts.
+    assert_eq!(tokenizer.tokenize("drive-thru"), vec![
+      Token::Word("drive".into()),
+      Token::Punctuation(Punctuation::Dash),
+      Token::Word("thru".into()),
+    ]);
+    // "double-check" isn't known whole, and neither part is in the dictionary, but we still
+    // split: the dictionary only gates whether the compound itself is known.
+    assert_eq!(tokenizer.tokenize("double-check"), vec![
+      Token::Word("double".into()),
+      Token::Punctuation(Punctuation::Dash),
+      Token::Word("check".into()),
+    ]);
+    // "haven't" is known whole, so it's left intact even though contraction splitting would
+    // otherwise apply.
+    assert_eq!(tokenizer.tokenize("haven't"), vec![
+      Token::Contraction("haven't".into()),
+    ]);
+    // "I'm" isn't known whole, so the dictionary splits it into stem and clitic.
+    assert_eq!(tokenizer.tokenize("I'm"), vec![
+      Token::Word("I".into()),
+      Token::Contraction("'m".into()),
+    ]);
+    // Without with_hyphenation_splitting, an unknown HyphenatedWord is left intact.
+    let tokenizer = SentenceTokenizer::new().with_dictionary(|_: &str| false);
+    assert_eq!(tokenizer.tokenize("drive-thru"), vec![
+      Token::HyphenatedWord("drive-thru".into()),
+    ]);
+  }
+
+  #[test]
+  fn lowercase_normalization() {
+    let tokenizer = SentenceTokenizer::new().with_lowercase_normalization(true);
+    assert_eq!(tokenizer.tokenize("HELLO World"), vec![
+      Token::Word("hello".into()),
+      Token::Word("world".into()),
+    ]);
+  }
+
+  #[test]
+  fn acronyms() {
+    let sentence = "I live in the U.S.A. and study for a Ph.D.";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("I".into()),
+      Token::Word("live".into()),
+      Token::Word("in".into()),
+      Token::Word("the".into()),
+      Token::Acronym("U.S.A.".into()),
+      Token::Word("and".into()),
+      Token::Word("study".into()),
+      Token::Word("for".into()),
+      Token::Word("a".into()),
+      Token::Acronym("Ph.D.".into()),
+    ]);
+    // Trailing sentence punctuation after an acronym is still peeled off.
+    let sentence = "Bring snacks, e.g. chips, or don't bother.";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("Bring".into()),
+      Token::Word("snacks".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Acronym("e.g.".into()),
+      Token::Word("chips".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Word("or".into()),
+      Token::Contraction("don't".into()),
+      Token::Word("bother".into()),
+      Token::Punctuation(Punctuation::Period),
+    ]);
+    // Known lowercase abbreviations keep their dot instead of it ending the sentence.
+    let sentence = "Mr. Smith vs. Dr. Jones";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Acronym("Mr.".into()),
+      Token::Word("Smith".into()),
+      Token::Acronym("vs.".into()),
+      Token::Acronym("Dr.".into()),
+      Token::Word("Jones".into()),
+    ]);
+    // An ordinary word with a trailing period is unaffected.
+    let sentence = "USA.";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("USA".into()),
+      Token::Punctuation(Punctuation::Period),
+    ]);
+    // Back-to-back acronyms with no separating whitespace are each recognized on their own,
+    // rather than gluing into a single mega-acronym.
+    let sentence = "U.S.A.Ph.D.";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Acronym("U.S.A.".into()),
+      Token::Acronym("Ph.D.".into()),
+    ]);
+  }
+
+  #[test]
+  fn currency_percentage_and_ordinals() {
+    let sentence = "It costs $5, or €10 if you prefer, which is $1,234.56 down from 50% off.";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("It".into()),
+      Token::Word("costs".into()),
+      Token::Currency("$5".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Word("or".into()),
+      Token::Currency("€10".into()),
+      Token::Word("if".into()),
+      Token::Word("you".into()),
+      Token::Word("prefer".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Word("which".into()),
+      Token::Word("is".into()),
+      Token::Currency("$1,234.56".into()),
+      Token::Word("down".into()),
+      Token::Word("from".into()),
+      Token::Percentage("50%".into()),
+      Token::Word("off".into()),
+      Token::Punctuation(Punctuation::Period),
+    ]);
+    let sentence = "A 3.5% raise for the 1st, 2nd, 22nd, 3rd, and 4th place finishers";
+    assert_eq!(tokenize(sentence), vec![
+      Token::Word("A".into()),
+      Token::Percentage("3.5%".into()),
+      Token::Word("raise".into()),
+      Token::Word("for".into()),
+      Token::Word("the".into()),
+      Token::Ordinal("1st".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Ordinal("2nd".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Ordinal("22nd".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Ordinal("3rd".into()),
+      Token::Punctuation(Punctuation::Comma),
+      Token::Word("and".into()),
+      Token::Ordinal("4th".into()),
+      Token::Word("place".into()),
+      Token::Word("finishers".into()),
+    ]);
+  }
+
+  #[test]
+  fn spans() {
+    let tokenizer = SentenceTokenizer::new();
+    let sentence = "This, right here, is a sentence.";
+    assert_eq!(tokenizer.tokenize_spanned(sentence), vec![
+      (TokenSpan { start: 0, end: 4 }, Token::Word("This".into())),
+      (TokenSpan { start: 4, end: 5 }, Token::Punctuation(Punctuation::Comma)),
+      (TokenSpan { start: 6, end: 11 }, Token::Word("right".into())),
+      (TokenSpan { start: 12, end: 16 }, Token::Word("here".into())),
+      (TokenSpan { start: 16, end: 17 }, Token::Punctuation(Punctuation::Comma)),
+      (TokenSpan { start: 18, end: 20 }, Token::Word("is".into())),
+      (TokenSpan { start: 21, end: 22 }, Token::Word("a".into())),
+      (TokenSpan { start: 23, end: 31 }, Token::Word("sentence".into())),
+      (TokenSpan { start: 31, end: 32 }, Token::Punctuation(Punctuation::Period)),
+    ]);
+    // Spans still line up after a token is split into several pieces (contraction, then
+    // trailing punctuation).
+    let sentence = "haven't.";
+    assert_eq!(tokenizer.tokenize_spanned(sentence), vec![
+      (TokenSpan { start: 0, end: 7 }, Token::Contraction("haven't".into())),
+      (TokenSpan { start: 7, end: 8 }, Token::Punctuation(Punctuation::Period)),
+    ]);
+    // tokenize() discards spans but otherwise matches tokenize_spanned().
+    assert_eq!(
+      tokenizer.tokenize(sentence),
+      tokenizer.tokenize_spanned(sentence).into_iter().map(|(_, token)| token).collect::<Vec<_>>(),
+    );
+  }
+
+  #[test]
+  fn token_stream_matches_tokenize_spanned() {
+    let tokenizer = SentenceTokenizer::new();
+    let sentence = "Mr. Smith paid $5, right? Great :) 1,000 thanks!";
+    let streamed: Vec<_> = tokenizer.token_stream(sentence).collect();
+    assert_eq!(streamed, tokenizer.tokenize_spanned(sentence));
+  }
+
   #[test]
   fn empty_strings() {
     // Empty.
@@ -602,7 +1744,7 @@ mod tests {
   }
 
   fn tokenize(sentence: &str) -> Vec<Token> {
-    let tokenizer = SentenceTokenizer {};
+    let tokenizer = SentenceTokenizer::new();
     tokenizer.tokenize(sentence)
   }
 }